@@ -0,0 +1,182 @@
+use serde_json;
+use std::io;
+use std::io::{BufRead, Read};
+use super::{ClockEvent, DerbyJSON, Jam, JamEvent, Note, Period, Timeout, Timers};
+
+/// A single message from a live game feed: an incremental update to a game
+/// that is still being officiated, as opposed to a complete file loaded all
+/// at once. A scoreboard or tracking front end applies these to a
+/// `DerbyJSON` as they arrive over a connection (WebSocket, line-delimited
+/// stdin, etc).
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum FeedMessage {
+    #[serde(rename = "jam event")]
+    JamEvent {
+        period: usize,
+        jam: u16,
+        event: JamEvent,
+    },
+    #[serde(rename = "jam start")]
+    JamStart { period: usize, jam: Jam },
+    #[serde(rename = "timeout")]
+    Timeout { period: usize, timeout: Timeout },
+    #[serde(rename = "timer update")]
+    TimerUpdate { timers: Timers },
+    #[serde(rename = "error")]
+    Error { errors: Vec<String> },
+}
+
+#[derive(Debug)]
+pub enum FeedError {
+    Serde(serde_json::Error),
+    Io(io::Error),
+    UnknownPeriod(usize),
+}
+
+impl From<serde_json::Error> for FeedError {
+    fn from(e: serde_json::Error) -> FeedError {
+        FeedError::Serde(e)
+    }
+}
+
+impl From<io::Error> for FeedError {
+    fn from(e: io::Error) -> FeedError {
+        FeedError::Io(e)
+    }
+}
+
+/// Apply one feed message to a live game, mutating it in place.
+///
+/// Periods are located by their 0-based index in `game.periods`, and jams
+/// by jam number, rather than assumed to be the last one in the list, since
+/// messages about earlier jams can arrive late or out of order. A `JamEvent` message for a jam whose `JamStart` hasn't
+/// been seen yet gets a bare `Jam` created for it, so the event isn't
+/// dropped. There's no separate roster-in-jam state to validate against, so
+/// a `Penalty` or `ExitBox` for a skater not yet seen in any `Lineup` event
+/// is simply pushed like any other event.
+pub fn apply(game: &mut DerbyJSON, msg: FeedMessage) -> Result<(), FeedError> {
+    match msg {
+        FeedMessage::JamStart { period, jam } => {
+            let period = get_period_mut(game, period)?;
+            match find_jam_mut(period, jam.number) {
+                // A stub may already exist if a `JamEvent` for this jam
+                // arrived before its `JamStart`; keep what it accumulated
+                // rather than discarding it.
+                Some(slot) => {
+                    slot.timestamp = jam.timestamp;
+                    slot.duration = jam.duration;
+                    slot.events.extend(jam.events);
+                    slot.notes.extend(jam.notes);
+                }
+                None => period.jams.push(ClockEvent::Jam(jam)),
+            }
+        }
+        FeedMessage::JamEvent { period, jam, event } => {
+            let period = get_period_mut(game, period)?;
+            if find_jam_mut(period, jam).is_none() {
+                period.jams.push(ClockEvent::Jam(Jam {
+                    number: jam,
+                    timestamp: None,
+                    duration: None,
+                    events: Vec::new(),
+                    notes: Vec::new(),
+                }));
+            }
+            let target = find_jam_mut(period, jam).expect("jam was just inserted");
+            target.events.push(event);
+        }
+        FeedMessage::Timeout { period, timeout } => {
+            let period = get_period_mut(game, period)?;
+            period.jams.push(ClockEvent::Timeout(timeout));
+        }
+        FeedMessage::TimerUpdate { timers } => {
+            game.timers = timers;
+        }
+        FeedMessage::Error { errors } => {
+            for message in errors {
+                game.notes.push(Note { note: message, author: None });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_period_mut(game: &mut DerbyJSON, index: usize) -> Result<&mut Period, FeedError> {
+    game.periods.get_mut(index).ok_or(FeedError::UnknownPeriod(index))
+}
+
+fn find_jam_mut(period: &mut Period, number: u16) -> Option<&mut Jam> {
+    for event in period.jams.iter_mut().rev() {
+        if let &mut ClockEvent::Jam(ref mut jam) = event {
+            if jam.number == number {
+                return Some(jam);
+            }
+        }
+    }
+    None
+}
+
+/// Read newline-delimited `FeedMessage`s from `reader`, applying each to
+/// `game` as it arrives. Blank lines are skipped; every other line must be
+/// one complete JSON object.
+pub fn drive<R>(game: &mut DerbyJSON, reader: R) -> Result<(), FeedError>
+    where R: Read
+{
+    let buf = io::BufReader::new(reader);
+    for line in buf.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let msg: FeedMessage = serde_json::from_str(&line)?;
+        apply(game, msg)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    #[test]
+    fn jam_start_after_jam_event_keeps_events_already_recorded() {
+        let mut game = DerbyJSON::new_game(HashMap::new());
+
+        apply(&mut game, FeedMessage::JamEvent {
+            period: 0,
+            jam: 1,
+            event: JamEvent::Lead { timestamp: None, skater: "123".to_string() },
+        }).unwrap();
+
+        apply(&mut game, FeedMessage::JamStart {
+            period: 0,
+            jam: Jam {
+                number: 1,
+                timestamp: None,
+                duration: Some(30),
+                events: Vec::new(),
+                notes: Vec::new(),
+            },
+        }).unwrap();
+
+        let jam = find_jam_mut(&mut game.periods[0], 1).expect("jam should exist");
+        assert_eq!(jam.duration, Some(30));
+        assert_eq!(jam.events.len(), 1);
+    }
+
+    #[test]
+    fn jam_event_before_jam_start_creates_a_stub() {
+        let mut game = DerbyJSON::new_game(HashMap::new());
+
+        apply(&mut game, FeedMessage::JamEvent {
+            period: 0,
+            jam: 7,
+            event: JamEvent::Lead { timestamp: None, skater: "99".to_string() },
+        }).unwrap();
+
+        let jam = find_jam_mut(&mut game.periods[0], 7).expect("stub jam should exist");
+        assert_eq!(jam.events.len(), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use serde_json;
 use super::Note;
+use super::PenaltyCode;
 
 /// This enum represents an event that happens during a game of derby, and is
 /// the main container for storing game data. Points, penalties, lineups,
@@ -26,7 +27,7 @@ pub enum JamEvent {
     Penalty {
         timestamp: Option<Timestamp>,
         skater: String,
-        penalty: String,
+        penalty: PenaltyCode,
         severity: Option<PenaltySeverity>,
         rescinded: Option<bool>,
         involved: Option<Vec<Involved>>,
@@ -117,26 +118,45 @@ pub enum JamEvent {
     // Action, Error,
 }
 
-/// A skater's position in a jam.
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum Position { Jammer, Pivot, Blocker }
+catchall_enum! {
+    /// A skater's position in a jam.
+    pub enum Position {
+        Jammer => "jammer",
+        Pivot => "pivot",
+        Blocker => "blocker",
+    }
+}
 
-/// Penalty severity.
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum PenaltySeverity { No, Minor, Major, Expulsion }
+catchall_enum! {
+    /// Penalty severity.
+    pub enum PenaltySeverity {
+        No => "no",
+        Minor => "minor",
+        Major => "major",
+        Expulsion => "expulsion",
+    }
+}
 
-/// Reason why a skater left the penalty box early: due to officiating error,
-/// skater leaving the box early, a rescinded penalty, or a skater who
-/// mistakenly reported to the box.
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum PrematureExitReason { Official, Skater, Rescinded, Mistake }
+catchall_enum! {
+    /// Reason why a skater left the penalty box early: due to officiating error,
+    /// skater leaving the box early, a rescinded penalty, or a skater who
+    /// mistakenly reported to the box.
+    pub enum PrematureExitReason {
+        Official => "official",
+        Skater => "skater",
+        Rescinded => "rescinded",
+        Mistake => "mistake",
+    }
+}
 
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum LeaveTrackReason { Penalty, Injury, Malfuction, Other }
+catchall_enum! {
+    pub enum LeaveTrackReason {
+        Penalty => "penalty",
+        Injury => "injury",
+        Malfuction => "malfuction",
+        Other => "other",
+    }
+}
     
 /// Represents a "ghost point" scored by means other than passing an
 /// opponent's hips
@@ -146,10 +166,49 @@ pub struct GhostPoint {
     pub ghost_point: GhostPointType,
 }
 
-/// Type of ghost point. Lap of jammer, Jammer in box, Blocker in box,
-/// Pivot in box, Not on the track, Out of play, Ghost point of unknown causes
-#[derive(Serialize, Deserialize)]
-pub enum GhostPointType { L, J, B, P, N, O, G }
+catchall_enum! {
+    /// Type of ghost point. Lap of jammer, Jammer in box, Blocker in box,
+    /// Pivot in box, Not on the track, Out of play, Ghost point of unknown causes
+    pub enum GhostPointType {
+        L => "L",
+        J => "J",
+        B => "B",
+        P => "P",
+        N => "N",
+        O => "O",
+        G => "G",
+    }
+}
+
+impl GhostPointType {
+    /// Full human-readable name for this ghost point type.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            GhostPointType::L => "Lap of Jammer",
+            GhostPointType::J => "Jammer in Box",
+            GhostPointType::B => "Blocker in Box",
+            GhostPointType::P => "Pivot in Box",
+            GhostPointType::N => "Not on the Track",
+            GhostPointType::O => "Out of Play",
+            GhostPointType::G => "Ghost Point of Unknown Cause",
+            GhostPointType::Unknown(_) => "Unrecognized Ghost Point",
+        }
+    }
+
+    /// Look up a `GhostPointType` from its single-letter wire code.
+    pub fn from_code(code: &str) -> Option<GhostPointType> {
+        Some(match code {
+            "L" => GhostPointType::L,
+            "J" => GhostPointType::J,
+            "B" => GhostPointType::B,
+            "P" => GhostPointType::P,
+            "N" => GhostPointType::N,
+            "O" => GhostPointType::O,
+            "G" => GhostPointType::G,
+            _ => return None,
+        })
+    }
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct Involved {
@@ -200,8 +259,13 @@ pub enum ClockEvent {
     Timeout(Timeout),
 }
 
-#[derive(Serialize, Deserialize)]
-pub enum TeamType { Home, Away, Officials }
+catchall_enum! {
+    pub enum TeamType {
+        Home => "Home",
+        Away => "Away",
+        Officials => "Officials",
+    }
+}
 
 #[derive(Serialize, Deserialize, Default)]
 pub struct Period {
@@ -9,10 +9,65 @@ extern crate serde_json;
 use std::collections::HashMap;
 use std::io::Read;
 
+/// Defines an enum whose known variants map to fixed wire-format strings,
+/// plus a catch-all `Unknown(String)` variant that preserves whatever token
+/// this version of the crate doesn't recognize. This keeps
+/// `serde_json::from_reader` from hard-failing on files written against a
+/// newer revision of the DerbyJSON spec, and round-trips unknown tokens
+/// byte-for-byte on re-serialization, while known tokens still serialize to
+/// their canonical spelling.
+macro_rules! catchall_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $variant:ident => $rename:expr ),+ $(,)*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $( $variant, )+
+            /// A token not recognized by this version of the spec.
+            Unknown(String),
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                let s: &str = match *self {
+                    $( $name::$variant => $rename, )+
+                    $name::Unknown(ref s) => s,
+                };
+                serializer.serialize_str(s)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                let s = String::deserialize(deserializer)?;
+                Ok(match s.as_str() {
+                    $( $rename => $name::$variant, )+
+                    _ => $name::Unknown(s),
+                })
+            }
+        }
+    }
+}
+
+mod penalty;
+pub use penalty::*;
 mod jamdata;
 pub use jamdata::*;
 mod teamdata;
 pub use teamdata::*;
+mod feed;
+pub use feed::*;
+mod stats;
+pub use stats::*;
+mod query;
+pub use query::*;
 
 /// Version of DerbyJSON supported
 pub const VERSION: &str = "0.2";
@@ -92,6 +147,21 @@ impl DerbyJSON {
             timers: timers,
         }
     }
+
+    /// Map each skater number to the key of the team it belongs to, by
+    /// walking `self.teams`. Used wherever a jam event needs to be
+    /// attributed to a team but only carries a skater number.
+    pub(crate) fn skater_team_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        for (team_key, team) in &self.teams {
+            for person in &team.persons {
+                if let Some(ref number) = person.number {
+                    map.insert(number.clone(), team_key.clone());
+                }
+            }
+        }
+        map
+    }
 }
 
 /// A subset of the general DerbyJSON object, just storing information on
@@ -126,9 +196,15 @@ impl Rosters {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
-#[serde(rename_all = "kebab-case")]
-pub enum ObjectType { Game, Rosters, Stats, League }
+catchall_enum! {
+    #[derive(PartialEq, Debug)]
+    pub enum ObjectType {
+        Game => "game",
+        Rosters => "rosters",
+        Stats => "stats",
+        League => "league",
+    }
+}
 
 #[derive(Serialize, Deserialize)]
 pub struct Expulsion {
@@ -215,6 +291,8 @@ pub fn load_roster<R>(reader: R) -> Result<Rosters, Error>
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
+    use super::ObjectType;
+
     #[test]
     fn it_works() {
         let text = include_bytes!("rosters.json");
@@ -225,4 +303,20 @@ mod tests {
         let djson = res.unwrap();
         assert!(djson.teams.len() == 2);
     }
+
+    #[test]
+    fn known_tokens_serialize_to_canonical_spelling() {
+        let league: ObjectType = serde_json::from_str("\"league\"").unwrap();
+        assert_eq!(serde_json::to_string(&league).unwrap(), "\"league\"");
+    }
+
+    #[test]
+    fn unknown_tokens_round_trip_losslessly() {
+        let unknown: ObjectType = serde_json::from_str("\"future-type\"").unwrap();
+        match unknown {
+            ObjectType::Unknown(ref s) => assert_eq!(s, "future-type"),
+            _ => panic!("expected ObjectType::Unknown"),
+        }
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"future-type\"");
+    }
 }
@@ -0,0 +1,138 @@
+/// A recognized WFTDA/MRDA penalty short code, mapped to its full
+/// human-readable name. The wire format is always the bare code string;
+/// `name()` gives the display text for building penalty sheets. Codes
+/// outside the recognized set fall back to `Unknown`, carrying the raw
+/// string, so files using league-specific or legacy codes still parse.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PenaltyCode {
+    CuttingTrack,
+    MultiplayerBlock,
+    BackBlock,
+    LowBlock,
+    DirectionOfPlay,
+    IllegalProcedure,
+    FalseStart,
+    GrossMisconduct,
+    HighBlock,
+    IllegalAssist,
+    IllegalPosition,
+    OutOfPlayBlock,
+    OutOfBounds,
+    IllegalContact,
+    DelayOfGame,
+    Unknown(String),
+}
+
+impl PenaltyCode {
+    /// The short code as it appears on the wire, e.g. `"X"`.
+    pub fn code(&self) -> &str {
+        match *self {
+            PenaltyCode::CuttingTrack => "X",
+            PenaltyCode::MultiplayerBlock => "A",
+            PenaltyCode::BackBlock => "B",
+            PenaltyCode::LowBlock => "C",
+            PenaltyCode::DirectionOfPlay => "D",
+            PenaltyCode::IllegalProcedure => "E",
+            PenaltyCode::FalseStart => "F",
+            PenaltyCode::GrossMisconduct => "G",
+            PenaltyCode::HighBlock => "H",
+            PenaltyCode::IllegalAssist => "I",
+            PenaltyCode::IllegalPosition => "L",
+            PenaltyCode::OutOfPlayBlock => "N",
+            PenaltyCode::OutOfBounds => "O",
+            PenaltyCode::IllegalContact => "P",
+            PenaltyCode::DelayOfGame => "Z",
+            PenaltyCode::Unknown(ref s) => s,
+        }
+    }
+
+    /// Full human-readable name of this penalty.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            PenaltyCode::CuttingTrack => "Cutting the Track",
+            PenaltyCode::MultiplayerBlock => "Multiplayer Block",
+            PenaltyCode::BackBlock => "Back Block",
+            PenaltyCode::LowBlock => "Low Block",
+            PenaltyCode::DirectionOfPlay => "Direction of Game Play",
+            PenaltyCode::IllegalProcedure => "Illegal Procedure",
+            PenaltyCode::FalseStart => "False Start",
+            PenaltyCode::GrossMisconduct => "Gross Misconduct",
+            PenaltyCode::HighBlock => "High Block",
+            PenaltyCode::IllegalAssist => "Illegal Assist",
+            PenaltyCode::IllegalPosition => "Illegal Position",
+            PenaltyCode::OutOfPlayBlock => "Out of Play Block",
+            PenaltyCode::OutOfBounds => "Out of Bounds",
+            PenaltyCode::IllegalContact => "Illegal Contact",
+            PenaltyCode::DelayOfGame => "Delay of Game",
+            PenaltyCode::Unknown(_) => "Unrecognized Penalty",
+        }
+    }
+
+    /// Look up a `PenaltyCode` from its short code. Returns `None` for a
+    /// code outside the recognized set; callers that need to keep
+    /// unrecognized codes around (rather than rejecting them) should wrap
+    /// the raw string in `PenaltyCode::Unknown` themselves, which is what
+    /// the `Deserialize` impl below does.
+    pub fn from_code(code: &str) -> Option<PenaltyCode> {
+        Some(match code {
+            "X" => PenaltyCode::CuttingTrack,
+            "A" => PenaltyCode::MultiplayerBlock,
+            "B" => PenaltyCode::BackBlock,
+            "C" => PenaltyCode::LowBlock,
+            "D" => PenaltyCode::DirectionOfPlay,
+            "E" => PenaltyCode::IllegalProcedure,
+            "F" => PenaltyCode::FalseStart,
+            "G" => PenaltyCode::GrossMisconduct,
+            "H" => PenaltyCode::HighBlock,
+            "I" => PenaltyCode::IllegalAssist,
+            "L" => PenaltyCode::IllegalPosition,
+            "N" => PenaltyCode::OutOfPlayBlock,
+            "O" => PenaltyCode::OutOfBounds,
+            "P" => PenaltyCode::IllegalContact,
+            "Z" => PenaltyCode::DelayOfGame,
+            _ => return None,
+        })
+    }
+}
+
+impl ::serde::Serialize for PenaltyCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::Serializer
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PenaltyCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: ::serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(PenaltyCode::from_code(&s).unwrap_or(PenaltyCode::Unknown(s)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_codes_round_trip_through_from_code_and_code() {
+        let low_block = PenaltyCode::from_code("C").unwrap();
+        assert_eq!(low_block, PenaltyCode::LowBlock);
+        assert_eq!(low_block.code(), "C");
+        assert_eq!(low_block.name(), "Low Block");
+    }
+
+    #[test]
+    fn unrecognized_codes_deserialize_to_unknown_and_round_trip() {
+        assert_eq!(PenaltyCode::from_code("Q"), None);
+
+        let unknown: PenaltyCode = serde_json::from_str("\"Q\"").unwrap();
+        match unknown {
+            PenaltyCode::Unknown(ref s) => assert_eq!(s, "Q"),
+            _ => panic!("expected PenaltyCode::Unknown"),
+        }
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"Q\"");
+    }
+}
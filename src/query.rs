@@ -0,0 +1,272 @@
+use super::{ClockEvent, DerbyJSON, JamEvent, TeamType, Timestamp};
+
+/// The discriminant of a `JamEvent`, used to filter a `GameQuery` down to
+/// one kind of event without matching on the full enum.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum JamEventKind {
+    Lineup,
+    PackLap,
+    Penalty,
+    Pass,
+    StarPass,
+    Lead,
+    LostLead,
+    Call,
+    EnterBox,
+    ExitBox,
+    BoxTime,
+    Injury,
+    Note,
+    LeaveTrack,
+    ReturnTrack,
+}
+
+impl JamEventKind {
+    fn of(event: &JamEvent) -> JamEventKind {
+        match *event {
+            JamEvent::Lineup { .. } => JamEventKind::Lineup,
+            JamEvent::PackLap { .. } => JamEventKind::PackLap,
+            JamEvent::Penalty { .. } => JamEventKind::Penalty,
+            JamEvent::Pass { .. } => JamEventKind::Pass,
+            JamEvent::StarPass { .. } => JamEventKind::StarPass,
+            JamEvent::Lead { .. } => JamEventKind::Lead,
+            JamEvent::LostLead { .. } => JamEventKind::LostLead,
+            JamEvent::Call { .. } => JamEventKind::Call,
+            JamEvent::EnterBox { .. } => JamEventKind::EnterBox,
+            JamEvent::ExitBox { .. } => JamEventKind::ExitBox,
+            JamEvent::BoxTime { .. } => JamEventKind::BoxTime,
+            JamEvent::Injury { .. } => JamEventKind::Injury,
+            JamEvent::Note { .. } => JamEventKind::Note,
+            JamEvent::LeaveTrack { .. } => JamEventKind::LeaveTrack,
+            JamEvent::ReturnTrack { .. } => JamEventKind::ReturnTrack,
+        }
+    }
+}
+
+/// A half-open window of game time, in seconds since the start of the game,
+/// used to filter events by `Timestamp`.
+#[derive(Clone, Copy)]
+struct Window {
+    start: f64,
+    end: f64,
+}
+
+/// A chainable filter over a loaded game's jam events, so callers can pull
+/// out a subset without hand-writing nested loops over
+/// `periods -> ClockEvent::Jam -> events`.
+///
+/// Each filter is optional; a `GameQuery` with nothing set matches every
+/// event in the game. Call `run` to collect matches as
+/// `(period_index, jam_number, &JamEvent)` triples.
+#[derive(Default)]
+pub struct GameQuery {
+    skater: Option<String>,
+    team: Option<TeamType>,
+    kind: Option<JamEventKind>,
+    window: Option<Window>,
+}
+
+impl GameQuery {
+    pub fn new() -> GameQuery {
+        GameQuery::default()
+    }
+
+    /// Only match events naming this skater number.
+    pub fn skater<S: Into<String>>(mut self, skater: S) -> GameQuery {
+        self.skater = Some(skater.into());
+        self
+    }
+
+    /// Only match events attributable to this team, via the skater roster
+    /// of the game the query is run against or, for `Call` and `StarPass`,
+    /// via the event's own `team` field.
+    pub fn team(mut self, team: TeamType) -> GameQuery {
+        self.team = Some(team);
+        self
+    }
+
+    /// Only match events of this kind, e.g. `JamEventKind::Penalty`.
+    pub fn kind(mut self, kind: JamEventKind) -> GameQuery {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Only match events whose timestamp falls within `[start, end)`,
+    /// measured in seconds since the start of the game. Events whose
+    /// timestamp is `None`, or in a unit that can't be normalized to game
+    /// seconds (`Wall`, `Period`, `Epoch`, and `Jam` all fail to normalize
+    /// here; only `Seconds` is already game-relative), are skipped rather
+    /// than matched.
+    pub fn between(mut self, start: f64, end: f64) -> GameQuery {
+        self.window = Some(Window { start, end });
+        self
+    }
+
+    /// Run the query against a loaded game, returning every matching event
+    /// along with the period and jam it occurred in.
+    pub fn run<'a>(&self, game: &'a DerbyJSON) -> Vec<(usize, u16, &'a JamEvent)> {
+        let skater_team = game.skater_team_map();
+        let mut matches = Vec::new();
+
+        for (period_index, period) in game.periods.iter().enumerate() {
+            for clock_event in &period.jams {
+                let jam = match *clock_event {
+                    ClockEvent::Jam(ref jam) => jam,
+                    _ => continue,
+                };
+                for event in &jam.events {
+                    if self.matches(event, &skater_team) {
+                        matches.push((period_index, jam.number, event));
+                    }
+                }
+            }
+        }
+        matches
+    }
+
+    fn matches(&self, event: &JamEvent, skater_team: &::std::collections::HashMap<String, String>) -> bool {
+        if let Some(ref wanted_skater) = self.skater {
+            if event_skater(event) != Some(wanted_skater.as_str()) {
+                return false;
+            }
+        }
+        if let Some(ref wanted_team) = self.team {
+            let via_skater = event_skater(event)
+                .and_then(|s| skater_team.get(s))
+                .map_or(false, |team_key| team_matches(wanted_team, team_key));
+            let via_field = event_team_field(event)
+                .map_or(false, |team_key| team_matches(wanted_team, team_key));
+            if !via_skater && !via_field {
+                return false;
+            }
+        }
+        if let Some(wanted_kind) = self.kind {
+            if JamEventKind::of(event) != wanted_kind {
+                return false;
+            }
+        }
+        if let Some(window) = self.window {
+            match event_timestamp(event).and_then(normalize_timestamp) {
+                Some(seconds) => {
+                    if seconds < window.start || seconds >= window.end {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// `TeamType` doesn't carry a team key, only a home/away/officials role, so
+/// matching it against the roster's team keys is necessarily a guess: we
+/// treat a team key equal to the `TeamType`'s wire spelling, ignoring ASCII
+/// case (the common convention of keying `teams` by "home"/"away"), as a
+/// match.
+fn team_matches(wanted: &TeamType, team_key: &str) -> bool {
+    let wanted_key = match *wanted {
+        TeamType::Home => "home",
+        TeamType::Away => "away",
+        TeamType::Officials => "officials",
+        TeamType::Unknown(ref s) => s.as_str(),
+    };
+    team_key.eq_ignore_ascii_case(wanted_key)
+}
+
+fn event_skater(event: &JamEvent) -> Option<&str> {
+    match *event {
+        JamEvent::Lineup { ref skater, .. } => Some(skater),
+        JamEvent::Penalty { ref skater, .. } => Some(skater),
+        JamEvent::Pass { skater: Some(ref skater), .. } => Some(skater),
+        JamEvent::StarPass { skater: Some(ref skater), .. } => Some(skater),
+        JamEvent::Lead { ref skater, .. } => Some(skater),
+        JamEvent::LostLead { ref skater, .. } => Some(skater),
+        JamEvent::Call { skater: Some(ref skater), .. } => Some(skater),
+        JamEvent::EnterBox { ref skater, .. } => Some(skater),
+        JamEvent::ExitBox { ref skater, .. } => Some(skater),
+        JamEvent::Injury { ref skater, .. } => Some(skater),
+        JamEvent::LeaveTrack { ref skater, .. } => Some(skater),
+        JamEvent::ReturnTrack { ref skater, .. } => Some(skater),
+        _ => None,
+    }
+}
+
+/// The event's own `team` field, for the event kinds that carry one
+/// directly (`Call`, `StarPass`) instead of only naming a skater.
+fn event_team_field(event: &JamEvent) -> Option<&str> {
+    match *event {
+        JamEvent::StarPass { team: Some(ref team), .. } => Some(team),
+        JamEvent::Call { team: Some(ref team), .. } => Some(team),
+        _ => None,
+    }
+}
+
+fn event_timestamp(event: &JamEvent) -> Option<&Timestamp> {
+    match *event {
+        JamEvent::PackLap { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::Penalty { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::Pass { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::StarPass { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::Lead { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::LostLead { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::Call { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::EnterBox { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::ExitBox { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::Injury { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::LeaveTrack { ref timestamp, .. } => timestamp.as_ref(),
+        JamEvent::ReturnTrack { ref timestamp, .. } => timestamp.as_ref(),
+        _ => None,
+    }
+}
+
+/// Normalize a `Timestamp` to seconds since the start of the game, where
+/// possible. Only `Seconds` is already expressed in that unit. `Wall` and
+/// `Period` are strings with no fixed conversion available here; `Epoch` is
+/// wall-clock time and `Jam` is elapsed time within its own jam, neither of
+/// which is comparable to a game-relative offset without knowing the
+/// game's start time, which this function doesn't have access to. All four
+/// are treated as incomparable and skipped, same as a missing timestamp.
+fn normalize_timestamp(timestamp: &Timestamp) -> Option<f64> {
+    match *timestamp {
+        Timestamp::Seconds(ref n) => n.as_f64(),
+        Timestamp::Epoch(_) => None,
+        Timestamp::Jam(_) => None,
+        Timestamp::Wall(_) => None,
+        Timestamp::Period(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn epoch_and_jam_timestamps_are_incomparable_to_game_seconds() {
+        let epoch = Timestamp::Epoch(serde_json::Number::from(1_600_000_000));
+        let jam_relative = Timestamp::Jam(serde_json::Number::from(10));
+        let seconds = Timestamp::Seconds(serde_json::Number::from(42));
+
+        assert_eq!(normalize_timestamp(&epoch), None);
+        assert_eq!(normalize_timestamp(&jam_relative), None);
+        assert_eq!(normalize_timestamp(&seconds), Some(42.0));
+    }
+
+    #[test]
+    fn team_filter_matches_star_pass_via_its_own_team_field() {
+        let event = JamEvent::StarPass {
+            timestamp: None,
+            skater: None,
+            team: Some("away".to_string()),
+            completed: None,
+            failure: None,
+        };
+        let query = GameQuery::new().team(TeamType::Away);
+        assert!(query.matches(&event, &HashMap::new()));
+
+        let query = GameQuery::new().team(TeamType::Home);
+        assert!(!query.matches(&event, &HashMap::new()));
+    }
+}
@@ -0,0 +1,314 @@
+use serde_json;
+use std::collections::HashMap;
+use super::{ClockEvent, DerbyJSON, Jam, JamEvent, ObjectType, Position};
+
+/// Aggregate totals for one skater, derived from the jam events that
+/// mention them.
+#[derive(Serialize, Deserialize, Default)]
+pub struct SkaterStats {
+    pub team: Option<String>,
+    pub points: u32,
+    pub lead_jammer_count: u32,
+    pub lost_lead_count: u32,
+    pub jams_played: u32,
+    pub box_time: u32,
+    /// Penalty counts keyed by penalty code.
+    pub penalties: HashMap<String, u32>,
+    /// Penalty counts keyed by severity (as it serializes on the wire).
+    pub penalties_by_severity: HashMap<String, u32>,
+}
+
+/// Aggregate totals for one team.
+#[derive(Serialize, Deserialize, Default)]
+pub struct TeamStats {
+    pub points: u32,
+    pub penalties: HashMap<String, u32>,
+}
+
+/// The result of `DerbyJSON::compute_stats`: per-skater and per-team
+/// aggregates derived by walking every jam's events.
+#[derive(Serialize, Deserialize, Default)]
+pub struct StatsReport {
+    pub skaters: HashMap<String, SkaterStats>,
+    pub teams: HashMap<String, TeamStats>,
+}
+
+impl StatsReport {
+    fn skater_mut(&mut self, skater: &str, skater_team: &HashMap<String, String>) -> &mut SkaterStats {
+        let entry = self.skaters.entry(skater.to_string()).or_default();
+        if entry.team.is_none() {
+            entry.team = skater_team.get(skater).cloned();
+        }
+        entry
+    }
+
+    fn team_mut(&mut self, team: &str) -> &mut TeamStats {
+        self.teams.entry(team.to_string()).or_default()
+    }
+}
+
+/// A DerbyJSON root object of type "stats": the computed `StatsReport`
+/// alongside the version/metadata envelope shared with `DerbyJSON` and
+/// `Rosters`, so the result can be written back out as a stats document.
+#[derive(Serialize, Deserialize)]
+pub struct Stats {
+    pub version: Option<String>,
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(rename = "type")]
+    pub objecttype: ObjectType,
+    pub skaters: HashMap<String, SkaterStats>,
+    pub teams: HashMap<String, TeamStats>,
+}
+
+impl Stats {
+    pub fn new(report: StatsReport) -> Stats {
+        Stats {
+            version: Some(super::VERSION.to_string()),
+            metadata: None,
+            objecttype: ObjectType::Stats,
+            skaters: report.skaters,
+            teams: report.teams,
+        }
+    }
+}
+
+impl DerbyJSON {
+    /// Walk every jam in every period and derive per-skater and per-team
+    /// statistical totals: jam points, lead jammer counts, penalty tallies,
+    /// box time, and jams played.
+    pub fn compute_stats(&self) -> StatsReport {
+        let skater_team = self.skater_team_map();
+        let mut report = StatsReport::default();
+
+        for period in &self.periods {
+            for clock_event in &period.jams {
+                if let ClockEvent::Jam(ref jam) = *clock_event {
+                    apply_jam(jam, &skater_team, &mut report);
+                }
+            }
+        }
+        report
+    }
+}
+
+fn apply_jam(jam: &Jam, skater_team: &HashMap<String, String>, report: &mut StatsReport) {
+    // Which skater currently holds the star, per team, so that points
+    // scored after a star pass are still attributed to the right skater
+    // even when a `Pass` doesn't name one directly.
+    let mut jammer: HashMap<String, String> = HashMap::new();
+    // Skaters currently in the box, paired up by `EnterBox`/`ExitBox`, along
+    // with the duration (if any) recorded on entry.
+    let mut open_box: HashMap<String, Option<serde_json::Number>> = HashMap::new();
+
+    for event in &jam.events {
+        match *event {
+            JamEvent::Lineup { ref skater, ref position, .. } => {
+                report.skater_mut(skater, skater_team).jams_played += 1;
+                if let Position::Jammer = *position {
+                    if let Some(team) = skater_team.get(skater) {
+                        jammer.insert(team.clone(), skater.clone());
+                    }
+                }
+            }
+            JamEvent::StarPass { ref skater, ref completed, .. } => {
+                if *completed != Some(false) {
+                    if let Some(team) = skater.as_ref().and_then(|s| skater_team.get(s)) {
+                        jammer.insert(team.clone(), skater.clone().unwrap());
+                    }
+                }
+            }
+            JamEvent::Pass { ref skater, points, ref completed, ref ghost_points, .. } => {
+                if *completed == Some(false) {
+                    continue;
+                }
+                // Only fall back to the tracked jammer when exactly one team
+                // has one: with both teams' jammers tracked simultaneously,
+                // picking either one when `skater` is absent would be a
+                // guess, not an attribution.
+                let scorer = skater.clone().or_else(|| {
+                    if jammer.len() == 1 {
+                        jammer.values().next().cloned()
+                    } else {
+                        None
+                    }
+                });
+                if let Some(ref scorer) = scorer {
+                    let points = points.unwrap_or(0) as u32;
+                    report.skater_mut(scorer, skater_team).points += points;
+                    if let Some(team) = skater_team.get(scorer) {
+                        report.team_mut(team).points += points;
+                    }
+                }
+                if let Some(ref ghosts) = *ghost_points {
+                    for ghost in ghosts {
+                        let recipient = ghost.skater.clone().or_else(|| scorer.clone());
+                        if let Some(ref recipient) = recipient {
+                            report.skater_mut(recipient, skater_team).points += 1;
+                            if let Some(team) = skater_team.get(recipient) {
+                                report.team_mut(team).points += 1;
+                            }
+                        }
+                    }
+                }
+            }
+            JamEvent::Lead { ref skater, .. } => {
+                report.skater_mut(skater, skater_team).lead_jammer_count += 1;
+            }
+            JamEvent::LostLead { ref skater, .. } => {
+                report.skater_mut(skater, skater_team).lost_lead_count += 1;
+            }
+            JamEvent::Penalty { ref skater, ref penalty, ref severity, ref rescinded, .. } => {
+                if *rescinded == Some(true) {
+                    continue;
+                }
+                let code = penalty.code().to_string();
+                let team = skater_team.get(skater).cloned();
+                let stats = report.skater_mut(skater, skater_team);
+                *stats.penalties.entry(code.clone()).or_insert(0) += 1;
+                if let Some(ref severity) = *severity {
+                    *stats.penalties_by_severity.entry(severity_label(severity)).or_insert(0) += 1;
+                }
+                if let Some(team) = team {
+                    *report.team_mut(&team).penalties.entry(code).or_insert(0) += 1;
+                }
+            }
+            JamEvent::EnterBox { ref skater, ref duration, .. } => {
+                open_box.insert(skater.clone(), duration.clone());
+            }
+            JamEvent::ExitBox { ref skater, ref duration, .. } => {
+                let entered = open_box.remove(skater).unwrap_or(None);
+                if let Some(seconds) = duration.clone().or(entered) {
+                    report.skater_mut(skater, skater_team).box_time += number_as_u32(&seconds);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn severity_label(severity: &super::PenaltySeverity) -> String {
+    serde_json::to_value(severity)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn number_as_u32(n: &serde_json::Number) -> u32 {
+    n.as_u64()
+        .map(|v| v as u32)
+        .or_else(|| n.as_f64().map(|v| v as u32))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+    use super::super::PenaltyCode;
+    use super::super::PenaltySeverity;
+    use super::super::{GhostPoint, GhostPointType};
+
+    fn skater_team() -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        map.insert("100".to_string(), "home".to_string());
+        map.insert("200".to_string(), "away".to_string());
+        map
+    }
+
+    fn jam(events: Vec<JamEvent>) -> Jam {
+        Jam { number: 1, timestamp: None, duration: None, notes: Vec::new(), events: events }
+    }
+
+    #[test]
+    fn rescinded_penalties_are_excluded() {
+        let jam = jam(vec![
+            JamEvent::Penalty {
+                timestamp: None,
+                skater: "100".to_string(),
+                penalty: PenaltyCode::LowBlock,
+                severity: Some(PenaltySeverity::Major),
+                rescinded: Some(true),
+                involved: None,
+                cue: None,
+            },
+        ]);
+        let mut report = StatsReport::default();
+        apply_jam(&jam, &skater_team(), &mut report);
+        assert!(report.skaters.get("100").is_none());
+    }
+
+    #[test]
+    fn incomplete_passes_are_skipped() {
+        let jam = jam(vec![
+            JamEvent::Pass {
+                timestamp: None,
+                completed: Some(false),
+                number: 1,
+                points: Some(4),
+                skater: Some("100".to_string()),
+                ghost_points: None,
+            },
+        ]);
+        let mut report = StatsReport::default();
+        apply_jam(&jam, &skater_team(), &mut report);
+        assert_eq!(report.skaters.get("100").map(|s| s.points), None);
+    }
+
+    #[test]
+    fn pass_without_skater_is_not_attributed_when_both_jammers_are_tracked() {
+        let jam = jam(vec![
+            JamEvent::Lineup { skater: "100".to_string(), start_in_box: false, position: Position::Jammer },
+            JamEvent::Lineup { skater: "200".to_string(), start_in_box: false, position: Position::Jammer },
+            JamEvent::Pass {
+                timestamp: None,
+                completed: None,
+                number: 1,
+                points: Some(4),
+                skater: None,
+                ghost_points: None,
+            },
+        ]);
+        let mut report = StatsReport::default();
+        apply_jam(&jam, &skater_team(), &mut report);
+        assert_eq!(report.skaters.get("100").map_or(0, |s| s.points), 0);
+        assert_eq!(report.skaters.get("200").map_or(0, |s| s.points), 0);
+    }
+
+    #[test]
+    fn pass_without_skater_is_attributed_to_the_sole_tracked_jammer() {
+        let jam = jam(vec![
+            JamEvent::Lineup { skater: "100".to_string(), start_in_box: false, position: Position::Jammer },
+            JamEvent::Pass {
+                timestamp: None,
+                completed: None,
+                number: 1,
+                points: Some(4),
+                skater: None,
+                ghost_points: None,
+            },
+        ]);
+        let mut report = StatsReport::default();
+        apply_jam(&jam, &skater_team(), &mut report);
+        assert_eq!(report.skaters.get("100").map_or(0, |s| s.points), 4);
+    }
+
+    #[test]
+    fn skaterless_ghost_point_is_attributed_to_the_resolved_scorer() {
+        let jam = jam(vec![
+            JamEvent::Lineup { skater: "100".to_string(), start_in_box: false, position: Position::Jammer },
+            JamEvent::Pass {
+                timestamp: None,
+                completed: None,
+                number: 1,
+                points: Some(4),
+                skater: None,
+                ghost_points: Some(vec![
+                    GhostPoint { skater: None, ghost_point: GhostPointType::L },
+                ]),
+            },
+        ]);
+        let mut report = StatsReport::default();
+        apply_jam(&jam, &skater_team(), &mut report);
+        assert_eq!(report.skaters.get("100").map_or(0, |s| s.points), 5);
+    }
+}
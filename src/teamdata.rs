@@ -21,11 +21,16 @@ pub struct Team {
     pub logo: Option<Logo>,
 }
 
-#[derive(Serialize, Deserialize)]
-pub enum TeamLevel {
-    #[serde(rename = "All Star")]
-    AllStar,
-    B, C, Rec, Officials, Home, Adhoc
+catchall_enum! {
+    pub enum TeamLevel {
+        AllStar => "All Star",
+        B => "B",
+        C => "C",
+        Rec => "Rec",
+        Officials => "Officials",
+        Home => "Home",
+        Adhoc => "Adhoc",
+    }
 }
 
 /// Information on a league (collection of teams)
@@ -86,8 +91,14 @@ pub struct Certification {
     pub endorsement: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
-pub enum Association { WFTDA, MRDA, JRDA, Other }
+catchall_enum! {
+    pub enum Association {
+        WFTDA => "WFTDA",
+        MRDA => "MRDA",
+        JRDA => "JRDA",
+        Other => "Other",
+    }
+}
 
 /// Represents a team or league logo. Each field may contain a URL to
 /// the appropriate size/style of team logo.